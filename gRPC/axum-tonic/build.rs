@@ -1,8 +1,31 @@
+use std::path::{Path, PathBuf};
+
+/// Recursively collects every `.proto` file under `dir`, so adding a new
+/// package is just dropping a file in `proto/` rather than editing this script.
+fn collect_protos(dir: &Path, out: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).expect("failed to read proto directory") {
+        let path = entry.expect("failed to read proto directory entry").path();
+        if path.is_dir() {
+            collect_protos(&path, out);
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("proto") {
+            out.push(path);
+        }
+    }
+}
+
 fn main() {
+    let proto_root = Path::new("proto");
+    let mut protos = Vec::new();
+    collect_protos(proto_root, &mut protos);
+    protos.sort();
+
+    // `include_file_descriptor_set!` always resolves its path relative to `$OUT_DIR`, so the
+    // descriptor has to be written there too, not at a crate-relative path.
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR not set by cargo");
+    let descriptor_path = PathBuf::from(out_dir).join("descriptor.bin");
+
     tonic_build::configure()
-        .file_descriptor_set_path("proto/helloworld_descriptor.bin")
-        .compile(&["proto/helloworld.proto"], &["proto"])
+        .file_descriptor_set_path(descriptor_path)
+        .compile(&protos, &[proto_root])
         .unwrap();
-    // tonic_build::compile_protos(proto/helloworld.proto).unwrap().;
-    // tonic_build::compile_protos("proto/helloworld.proto").expect("Failed to compile protos");
 }
@@ -0,0 +1,52 @@
+use axum_tonic::helloworld::greeter_client::GreeterClient;
+use axum_tonic::helloworld::HelloRequest;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig};
+use tonic_health::pb::health_client::HealthClient;
+use tonic_health::pb::HealthCheckRequest;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The server terminates TLS with the bundled self-signed dev cert (see `load_tls_config`
+    // in `main.rs`), so the client has to trust that same cert as its root instead of the
+    // public CA roots, and dial the "localhost" name its SAN was issued for.
+    let dev_cert_pem = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/certs/dev-cert.pem"
+    ))?;
+    let tls_config = ClientTlsConfig::new()
+        .ca_certificate(Certificate::from_pem(dev_cert_pem))
+        .domain_name("localhost");
+
+    // Dial the literal address the server binds (`[::1]:50052`) rather than resolving
+    // "localhost", which isn't guaranteed to map to the IPv6 loopback; `domain_name` above
+    // still pins TLS verification to the name the dev cert's SAN was issued for.
+    let channel = Channel::from_static("https://[::1]:50052")
+        .tls_config(tls_config)?
+        .connect()
+        .await?;
+
+    let mut health_client = HealthClient::new(channel.clone());
+    let health = health_client
+        .check(HealthCheckRequest {
+            service: "helloworld.Greeter".to_string(),
+        })
+        .await?;
+    println!("Health: {:?}", health.into_inner().status());
+
+    let mut greeter_client = GreeterClient::new(channel);
+    let mut request = tonic::Request::new(HelloRequest {
+        name: "Tonic".to_string(),
+        count: 1,
+    });
+    // Read the same `AUTH_TOKEN` override the server's `AuthInterceptor` honors, so the
+    // probe client still works when the server isn't running with the default dev token.
+    let auth_token = std::env::var("AUTH_TOKEN").unwrap_or_else(|_| "dev-token".to_string());
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {auth_token}").parse()?);
+
+    let response = greeter_client.say_hello(request).await?;
+    println!("Response: {:?}", response.into_inner());
+
+    Ok(())
+}
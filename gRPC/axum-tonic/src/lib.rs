@@ -0,0 +1,13 @@
+pub mod basic {
+    tonic::include_proto!("basic");
+}
+
+pub mod helloworld {
+    tonic::include_proto!("helloworld"); // The string specified here must match the proto package name
+
+    pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("descriptor");
+}
+
+pub mod goodbye {
+    tonic::include_proto!("goodbye");
+}
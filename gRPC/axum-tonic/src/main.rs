@@ -1,18 +1,18 @@
-use axum::{response::Html, routing::get, Router};
-use helloworld::greeter_server::{Greeter, GreeterServer};
-use helloworld::{HelloReply, HelloRequest};
+use axum::{response::Html, routing::get};
+use axum_server::tls_rustls::RustlsConfig;
+use axum_tonic::basic::Empty;
+use axum_tonic::goodbye::goodbye_server::{Goodbye, GoodbyeServer};
+use axum_tonic::goodbye::{GoodbyeReply, GoodbyeRequest};
+use axum_tonic::helloworld::greeter_server::{Greeter, GreeterServer};
+use axum_tonic::helloworld::{self, HelloReply, HelloRequest};
+use std::pin::Pin;
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream};
 use tonic::{
-    transport::Server as tonic_server, Request as GRPC_Request, Response as GRPC_Response, Status,
+    service::Interceptor, transport::Server as tonic_server, Request as GRPC_Request,
+    Response as GRPC_Response, Status,
 };
-
-pub mod helloworld {
-
-    tonic::include_proto!("helloworld"); // The string specified here must match the proto package name
-                                         // let descriptor_path = PathBuf::from("/Users/hacker/Dev/projects/Jarvis/apps/desktop/src-tauri/proto");
-                                         // let descriptor_path = PathBuf::from(PathBuf::from(format!("/Users/hacker/Dev/projects/Jarvis/apps/desktop/src-tauri/proto")).join("my_descriptor.bin"));
-                                         // pub(crate) const FILE_DESCRIPTOR_SET: &[u8] =
-                                         //     tonic::include_file_descriptor_set!("helloworld_descriptor");
-}
+use tower_http::cors::CorsLayer;
 
 #[derive(Debug, Default)]
 pub struct MyGreeter {}
@@ -24,7 +24,9 @@ impl Greeter for MyGreeter {
         request: GRPC_Request<HelloRequest>, // Accept request of type HelloRequest
     ) -> Result<GRPC_Response<HelloReply>, Status> {
         // Return an instance of type HelloReply
-        println!("Got a request: {:?}", request);
+        // Log the message body only — the full `Request` Debug dump includes the
+        // `authorization` metadata, which would otherwise leak the bearer token.
+        println!("Got a request: {:?}", request.get_ref());
 
         let reply = helloworld::HelloReply {
             message: format!("Hello {}!", request.into_inner().name), // We must use .into_inner() as the fields of gRPC requests and responses are private
@@ -32,22 +34,157 @@ impl Greeter for MyGreeter {
 
         Ok(GRPC_Response::new(reply)) // Send back our formatted greeting
     }
+
+    type SayHelloStreamStream = Pin<Box<dyn Stream<Item = Result<HelloReply, Status>> + Send>>;
+
+    async fn say_hello_stream(
+        &self,
+        request: GRPC_Request<HelloRequest>,
+    ) -> Result<GRPC_Response<Self::SayHelloStreamStream>, Status> {
+        // See `say_hello` above — log the message body only, not the `authorization` metadata.
+        println!("Got a streaming request: {:?}", request.get_ref());
+
+        let HelloRequest { name, count } = request.into_inner();
+        let count = count.max(1);
+        let (tx, rx) = mpsc::channel(4);
+
+        tokio::spawn(async move {
+            for i in 1..=count {
+                let reply = helloworld::HelloReply {
+                    message: format!("Hello {name}! ({i}/{count})"),
+                };
+                if tx.send(Ok(reply)).await.is_err() {
+                    break; // Receiver dropped, client disconnected.
+                }
+                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+            }
+        });
+
+        Ok(GRPC_Response::new(
+            Box::pin(ReceiverStream::new(rx)) as Self::SayHelloStreamStream
+        ))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MyGoodbye {}
+
+#[tonic::async_trait]
+impl Goodbye for MyGoodbye {
+    async fn say_goodbye(
+        &self,
+        request: GRPC_Request<GoodbyeRequest>,
+    ) -> Result<GRPC_Response<GoodbyeReply>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let reply = GoodbyeReply {
+            message: format!("Goodbye {}!", request.into_inner().name),
+        };
+
+        Ok(GRPC_Response::new(reply))
+    }
+
+    async fn ping(&self, request: GRPC_Request<Empty>) -> Result<GRPC_Response<Empty>, Status> {
+        println!("Got a request: {:?}", request);
+        Ok(GRPC_Response::new(Empty {}))
+    }
 }
 
 async fn handler() -> Html<&'static str> {
     Html("<h1>Hello, World!</h1>")
 }
 
+/// Loads the TLS cert/key pair to terminate TLS on, preferring `TLS_CERT_PATH`/`TLS_KEY_PATH`
+/// and falling back to the bundled self-signed dev cert under `certs/`.
+async fn load_tls_config() -> RustlsConfig {
+    let cert_path = std::env::var("TLS_CERT_PATH")
+        .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/certs/dev-cert.pem").to_string());
+    let key_path = std::env::var("TLS_KEY_PATH")
+        .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/certs/dev-key.pem").to_string());
+
+    RustlsConfig::from_pem_file(cert_path, key_path)
+        .await
+        .expect("failed to load TLS cert/key pair")
+}
+
+/// Rejects `Greeter` calls whose `authorization` metadata doesn't carry the configured
+/// bearer token. The axum `/` HTML route sits outside the tonic service stack, so it stays
+/// unauthenticated.
+#[derive(Clone)]
+struct AuthInterceptor {
+    token: String,
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, request: GRPC_Request<()>) -> Result<GRPC_Request<()>, Status> {
+        let expected = format!("Bearer {}", self.token);
+        match request.metadata().get("authorization") {
+            Some(value) if value.to_str().map(|v| v == expected).unwrap_or(false) => Ok(request),
+            _ => Err(Status::unauthenticated("missing or invalid bearer token")),
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() {
     let addr = "[::1]:50052".parse().unwrap();
     let greeter = MyGreeter::default();
+    let goodbye = MyGoodbye::default();
+
+    let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
+    health_reporter
+        .set_serving::<GreeterServer<MyGreeter>>()
+        .await;
+
+    let reflection_service = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(helloworld::FILE_DESCRIPTOR_SET)
+        .build()
+        .expect("failed to build reflection service");
 
+    let auth_interceptor = AuthInterceptor {
+        token: std::env::var("AUTH_TOKEN").unwrap_or_else(|_| "dev-token".to_string()),
+    };
+
+    // gRPC-Web framing lets browser clients call `say_hello` over HTTP/1.1 without a proxy.
+    let greeter_service = tonic_web::enable(GreeterServer::with_interceptor(
+        greeter,
+        auth_interceptor,
+    ));
+
+    // `tonic_server::builder()`'s own layer stack never runs: we serve the resulting axum
+    // `Router` via `axum_server` below, not `tonic_server`'s own `Server::serve`, so the CORS
+    // layer has to be applied to the router `into_router()` returns instead.
     let router = tonic_server::builder()
-        .add_service(helloworld::greeter_server::GreeterServer::new(greeter))
+        .add_service(greeter_service)
+        .add_service(GoodbyeServer::new(goodbye))
+        .add_service(reflection_service)
+        .add_service(health_service)
         .into_router()
-        .route("/", get(handler));
-    axum::Server::bind(&addr)
+        .route("/", get(handler))
+        .layer(CorsLayer::permissive());
+
+    // `into_router()` flattens the axum `/` route and the gRPC services into one `Router`,
+    // so TLS has to terminate at the shared listener rather than inside `tonic_server`.
+    // `RustlsConfig` advertises `h2` and `http/1.1` via ALPN, so gRPC (h2) and the HTML
+    // handler (either protocol) negotiate correctly off the same TLS socket.
+    let tls_config = load_tls_config().await;
+
+    // `Handle::graceful_shutdown` lets in-flight requests finish instead of cutting them off,
+    // so health flips to NOT_SERVING first and existing connections still get to drain.
+    let handle = axum_server::Handle::new();
+    let shutdown_handle = handle.clone();
+    tokio::spawn(async move {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen for ctrl_c");
+        health_reporter
+            .set_not_serving::<GreeterServer<MyGreeter>>()
+            .await;
+        shutdown_handle.graceful_shutdown(Some(std::time::Duration::from_secs(10)));
+    });
+
+    axum_server::bind_rustls(addr, tls_config)
+        .handle(handle)
         .serve(router.into_make_service())
         .await
         .expect("server failed");